@@ -1,5 +1,93 @@
 use super::crc7;
 
+/// Build the six-byte command frame sent to the card.
+///
+/// The frame is the start bits and `command` index, the big-endian 32-bit
+/// `arg` and the CRC7 end byte, matching the SD command format. Shared by the
+/// blocking and async transports so the framing lives in exactly one place.
+#[inline]
+fn card_command_buffer(command: u8, arg: u32) -> [u8; 6] {
+    let mut buf = [
+        0x40 | command,
+        (arg >> 24) as u8,
+        (arg >> 16) as u8,
+        (arg >> 8) as u8,
+        arg as u8,
+        0,
+    ];
+    buf[5] = crc7(&buf[0..5]);
+    buf
+}
+
+/// Data-packet start token for single-block reads and writes.
+const DATA_START_BLOCK: u8 = 0xFE;
+
+/// Decode an SD data-response token (`xxx0_sss1`) returned after a data write.
+///
+/// The `sss` field distinguishes "data accepted" (`010`) from a CRC error
+/// (`101`) and a write error (`110`); the latter two are surfaced as
+/// [`TransportError::CrcMismatch`] and [`TransportError::CardError`]
+/// respectively so callers can retry a corrupted packet but give up on a
+/// genuine write failure.
+fn check_data_response<E>(token: u8) -> Result<(), TransportError<E>> {
+    match token & 0x1F {
+        0x05 => Ok(()),                        // sss = 010, data accepted
+        0x0B => Err(TransportError::CrcMismatch), // sss = 101, CRC error
+        _ => Err(TransportError::CardError(token)), // sss = 110 write error, or unknown
+    }
+}
+
+/// Compute the CRC16-CCITT over an SD data block.
+///
+/// Uses the `0x1021` polynomial with a zero seed, as the SD spec defines for
+/// the 16-bit CRC trailing every data block. Companion to the command-level
+/// [`crc7`](super::crc7).
+#[inline]
+fn crc16(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0;
+    for &byte in data {
+        crc ^= (byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+/// Error raised by a [`Transport`], distinguishing bus failures from
+/// card-level protocol problems.
+///
+/// The blocking and async SPI transports expose the raw bus error as their
+/// associated `Error` type; this enum is what the typed helper methods return
+/// so that higher layers can tell a wiring fault ([`Spi`]) apart from a card
+/// that never answered ([`NoResponse`]), rejected a command ([`CardError`]),
+/// stayed busy too long ([`Timeout`]) or exchanged a data block whose CRC did
+/// not match ([`CrcMismatch`]).
+///
+/// [`Spi`]: TransportError::Spi
+/// [`NoResponse`]: TransportError::NoResponse
+/// [`CardError`]: TransportError::CardError
+/// [`Timeout`]: TransportError::Timeout
+/// [`CrcMismatch`]: TransportError::CrcMismatch
+#[derive(Debug)]
+pub enum TransportError<E> {
+    /// An error from the underlying SPI bus.
+    Spi(E),
+    /// The card produced no valid response within the retry budget.
+    NoResponse,
+    /// The card reported an error, carrying the offending R1 response byte.
+    CardError(u8),
+    /// The card stayed busy beyond the allowed number of polls.
+    Timeout,
+    /// A data block failed its CRC16 check: either a block read from the card,
+    /// or a written block that the card's data-response token rejected.
+    CrcMismatch,
+}
+
 /// Abstract SD card transportation interface.
 pub trait Transport {
     /// Transport error type.
@@ -23,11 +111,56 @@ pub trait Transport {
     /// Read data from the card.
     fn read_data(&mut self, buf: &mut [u8]) -> Result<(), Self::Error>;
 
-    /// Try to flush the card.
-    fn flush_card(&mut self) -> Result<(), Self::Error>;
-
     /// Gets if the card is busy.
     fn is_busy(&mut self) -> Result<bool, Self::Error>;
+
+    /// Read the R1 response, retrying until the card drives the top bit low.
+    ///
+    /// The card holds MISO high (`0xFF`) until it answers, so this loops on
+    /// [`read_card_response_u8`](Transport::read_card_response_u8) up to
+    /// `max_retries` times. It returns [`TransportError::NoResponse`] if the
+    /// budget is exhausted, [`TransportError::CardError`] carrying the R1 byte
+    /// if any error bit is set, and the R1 byte otherwise.
+    fn read_r1_with_timeout(
+        &mut self,
+        max_retries: usize,
+    ) -> Result<u8, TransportError<Self::Error>> {
+        for _ in 0..max_retries {
+            let r1 = self
+                .read_card_response_u8()
+                .map_err(TransportError::Spi)?;
+            if r1 & 0x80 == 0 {
+                if r1 & 0x7E != 0 {
+                    return Err(TransportError::CardError(r1));
+                }
+                return Ok(r1);
+            }
+        }
+        Err(TransportError::NoResponse)
+    }
+
+    /// Poll [`is_busy`](Transport::is_busy) up to `max_polls` times.
+    ///
+    /// Returns once the card releases the bus, or
+    /// [`TransportError::Timeout`] if it is still busy after `max_polls`
+    /// polls. This replaces the old hard-coded `0..0xFF` spin with a
+    /// caller-supplied budget.
+    fn wait_not_busy(&mut self, max_polls: usize) -> Result<(), TransportError<Self::Error>> {
+        for _ in 0..max_polls {
+            if !self.is_busy().map_err(TransportError::Spi)? {
+                return Ok(());
+            }
+        }
+        Err(TransportError::Timeout)
+    }
+
+    /// Wait for the card to finish any in-flight write.
+    ///
+    /// Delegates to [`wait_not_busy`](Transport::wait_not_busy) with the
+    /// historical `0xFF`-poll budget.
+    fn flush_card(&mut self) -> Result<(), TransportError<Self::Error>> {
+        self.wait_not_busy(0xFF)
+    }
 }
 
 /// SPI as an abstract SD card transportation.
@@ -80,15 +213,7 @@ where
 
     #[inline]
     fn write_card_command(&mut self, command: u8, arg: u32) -> Result<(), Self::Error> {
-        let mut buf = [
-            0x40 | command,
-            (arg >> 24) as u8,
-            (arg >> 16) as u8,
-            (arg >> 8) as u8,
-            arg as u8,
-            0,
-        ];
-        buf[5] = crc7(&buf[0..5]);
+        let buf = card_command_buffer(command, arg);
         self.spi.write(&buf)
     }
 
@@ -128,15 +253,505 @@ where
     }
 
     #[inline]
-    fn flush_card(&mut self) -> Result<(), Self::Error> {
-        // Try flushing the card as done here:
-        // https://github.com/greiman/SdFat/blob/master/src/SdCard/SdSpiCard.cpp#L170,
-        // https://github.com/rust-embedded-community/embedded-sdmmc-rs/pull/65#issuecomment-1270709448
+    fn is_busy(&mut self) -> Result<bool, Self::Error> {
+        match self.transfer_byte(0xFF)? {
+            0xFF => Ok(false),
+            _ => Ok(true),
+        }
+    }
+}
+
+/// Abstract SD card transportation interface for `async` runtimes.
+///
+/// Mirrors [`Transport`], but every method is an `async fn` so block reads and
+/// writes can hand control back to the executor instead of blocking it while a
+/// DMA-backed SPI transfer is in flight.
+// The transport is driven from a single task per card, so the lack of an
+// auto-trait (`Send`) bound on the returned futures is fine; allow the bare
+// `async fn` form rather than spelling out `impl Future` on every method.
+#[allow(async_fn_in_trait)]
+pub trait AsyncTransport {
+    /// Transport error type.
+    type Error;
+
+    /// Write command and argument to the card.
+    async fn write_card_command(&mut self, command: u8, arg: u32) -> Result<(), Self::Error>;
+
+    /// Read one-byte card response from the card.
+    async fn read_card_response_u8(&mut self) -> Result<u8, Self::Error>;
+
+    /// Read four-byte card response from the card.
+    async fn read_card_response_u32(&mut self) -> Result<u32, Self::Error>;
+
+    /// Read sixteen-byte card response from the card.
+    async fn read_card_response_u128(&mut self) -> Result<u128, Self::Error>;
+
+    /// Write data to the card.
+    async fn write_data(&mut self, buf: &[u8]) -> Result<(), Self::Error>;
+
+    /// Read data from the card.
+    async fn read_data(&mut self, buf: &mut [u8]) -> Result<(), Self::Error>;
+
+    /// Gets if the card is busy.
+    async fn is_busy(&mut self) -> Result<bool, Self::Error>;
+
+    /// Read the R1 response, retrying until the card drives the top bit low.
+    ///
+    /// The async counterpart of
+    /// [`Transport::read_r1_with_timeout`]; see it for the return semantics.
+    async fn read_r1_with_timeout(
+        &mut self,
+        max_retries: usize,
+    ) -> Result<u8, TransportError<Self::Error>> {
+        for _ in 0..max_retries {
+            let r1 = self
+                .read_card_response_u8()
+                .await
+                .map_err(TransportError::Spi)?;
+            if r1 & 0x80 == 0 {
+                if r1 & 0x7E != 0 {
+                    return Err(TransportError::CardError(r1));
+                }
+                return Ok(r1);
+            }
+        }
+        Err(TransportError::NoResponse)
+    }
+
+    /// Poll [`is_busy`](AsyncTransport::is_busy) up to `max_polls` times,
+    /// yielding to the executor between polls.
+    ///
+    /// Returns once the card releases the bus, or
+    /// [`TransportError::Timeout`] if it is still busy after `max_polls`
+    /// polls. Yielding lets other tasks run while a multi-block write commits
+    /// to flash.
+    async fn wait_not_busy(&mut self, max_polls: usize) -> Result<(), TransportError<Self::Error>> {
+        for _ in 0..max_polls {
+            if !self.is_busy().await.map_err(TransportError::Spi)? {
+                return Ok(());
+            }
+            YieldNow::default().await;
+        }
+        Err(TransportError::Timeout)
+    }
+
+    /// Wait for the card to finish any in-flight write.
+    ///
+    /// Delegates to [`wait_not_busy`](AsyncTransport::wait_not_busy) with the
+    /// historical `0xFF`-poll budget.
+    async fn flush_card(&mut self) -> Result<(), TransportError<Self::Error>> {
+        self.wait_not_busy(0xFF).await
+    }
+}
+
+/// A future that yields to the executor once before completing.
+///
+/// Used by [`AsyncTransport::wait_not_busy`] to give other tasks a chance to
+/// run between busy polls without pulling in a timer dependency.
+#[derive(Default)]
+struct YieldNow {
+    yielded: bool,
+}
+
+impl core::future::Future for YieldNow {
+    type Output = ();
+
+    fn poll(
+        mut self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<()> {
+        if self.yielded {
+            core::task::Poll::Ready(())
+        } else {
+            self.yielded = true;
+            cx.waker().wake_by_ref();
+            core::task::Poll::Pending
+        }
+    }
+}
+
+/// Async SPI as an abstract SD card transportation.
+pub struct AsyncSpiTransport<SPI> {
+    spi: SPI,
+}
+
+impl<SPI> AsyncSpiTransport<SPI>
+where
+    SPI: embedded_hal_async::spi::SpiDevice<u8>,
+{
+    /// Create a new SD/MMC transpotation interface using a raw async SPI interface.
+    #[inline]
+    pub fn new(spi: SPI) -> Self {
+        Self { spi }
+    }
+    /// Get a temporary borrow on the underlying SPI device.
+    #[inline]
+    pub fn spi<T, F>(&mut self, func: F) -> T
+    where
+        F: FnOnce(&mut SPI) -> T,
+    {
+        func(&mut self.spi)
+    }
+    /// Release the underlying SPI and free the interface.
+    #[inline]
+    pub fn free(self) -> SPI {
+        self.spi
+    }
+}
+
+impl<SPI> AsyncSpiTransport<SPI>
+where
+    SPI: embedded_hal_async::spi::SpiDevice<u8>,
+{
+    /// Send one byte and receive one byte from the card.
+    #[inline]
+    async fn transfer_byte(&mut self, byte: u8) -> Result<u8, SPI::Error> {
+        let mut read_buf = [0u8; 1];
+        self.spi.transfer(&mut read_buf, &[byte]).await?;
+        Ok(read_buf[0])
+    }
+}
+
+impl<SPI> AsyncTransport for AsyncSpiTransport<SPI>
+where
+    SPI: embedded_hal_async::spi::SpiDevice<u8>,
+{
+    type Error = SPI::Error;
+
+    #[inline]
+    async fn write_card_command(&mut self, command: u8, arg: u32) -> Result<(), Self::Error> {
+        let buf = card_command_buffer(command, arg);
+        self.spi.write(&buf).await
+    }
+
+    #[inline]
+    async fn read_card_response_u8(&mut self) -> Result<u8, Self::Error> {
+        let mut read_buf = [0];
+        let write_buf = [0xFF];
+        self.spi.transfer(&mut read_buf, &write_buf).await?;
+        Ok(read_buf[0])
+    }
+
+    #[inline]
+    async fn read_card_response_u32(&mut self) -> Result<u32, Self::Error> {
+        let mut read_buf = [0; 4];
+        let write_buf = [0xFF; 4];
+        self.spi.transfer(&mut read_buf, &write_buf).await?;
+        Ok(u32::from_be_bytes(read_buf))
+    }
+
+    #[inline]
+    async fn read_card_response_u128(&mut self) -> Result<u128, Self::Error> {
+        let mut read_buf = [0; 16];
+        let write_buf = [0xFF; 16];
+        self.spi.transfer(&mut read_buf, &write_buf).await?;
+        Ok(u128::from_be_bytes(read_buf))
+    }
+
+    #[inline]
+    async fn write_data(&mut self, buf: &[u8]) -> Result<(), Self::Error> {
+        self.spi.write(&buf).await
+    }
+
+    #[inline]
+    async fn read_data(&mut self, buf: &mut [u8]) -> Result<(), Self::Error> {
+        buf.fill(0xFF);
+        self.spi.transfer_in_place(buf).await
+    }
+
+    #[inline]
+    async fn is_busy(&mut self) -> Result<bool, Self::Error> {
+        match self.transfer_byte(0xFF).await? {
+            0xFF => Ok(false),
+            _ => Ok(true),
+        }
+    }
+}
+
+/// SPI bus plus a manually-driven chip-select line as an abstract SD card
+/// transportation.
+///
+/// Unlike [`SpiTransport`], which relies on [`SpiDevice`] asserting and
+/// deasserting the chip-select around every transfer, this transport owns the
+/// raw [`SpiBus`] and the `CS` [`OutputPin`] directly. That lets the caller
+/// hold CS *high* while clocking the dummy bytes the card needs to enter SPI
+/// mode at power-up — a sequence that cannot be expressed through a
+/// [`SpiDevice`].
+///
+/// [`SpiDevice`]: embedded_hal::spi::SpiDevice
+/// [`SpiBus`]: embedded_hal::spi::SpiBus
+/// [`OutputPin`]: embedded_hal::digital::OutputPin
+pub struct SpiBusTransport<BUS, CS> {
+    bus: BUS,
+    cs: CS,
+}
+
+/// Error returned by [`SpiBusTransport`], combining a bus error and a
+/// chip-select pin error into one type.
+#[derive(Debug)]
+pub enum SpiBusError<BusError, PinError> {
+    /// An error from the underlying SPI bus.
+    Bus(BusError),
+    /// An error while driving the chip-select pin.
+    Pin(PinError),
+}
+
+impl<BUS, CS> SpiBusTransport<BUS, CS>
+where
+    BUS: embedded_hal::spi::SpiBus<u8>,
+    CS: embedded_hal::digital::OutputPin,
+{
+    /// Create a new SD/MMC transpotation interface owning a raw SPI bus and its
+    /// chip-select pin.
+    #[inline]
+    pub fn new(bus: BUS, cs: CS) -> Self {
+        Self { bus, cs }
+    }
+    /// Get a temporary borrow on the underlying SPI bus.
+    #[inline]
+    pub fn bus<T, F>(&mut self, func: F) -> T
+    where
+        F: FnOnce(&mut BUS) -> T,
+    {
+        func(&mut self.bus)
+    }
+    /// Release the underlying SPI bus and chip-select pin and free the interface.
+    #[inline]
+    pub fn free(self) -> (BUS, CS) {
+        (self.bus, self.cs)
+    }
+
+    /// Clock out the cold-init sequence needed to put the card into SPI mode
+    /// and return the CMD0 R1 response.
+    ///
+    /// With CS deasserted (held high), at least 74 clock cycles are sent as ten
+    /// dummy `0xFF` bytes; CS is then asserted, CMD0 (`GO_IDLE_STATE`) is
+    /// written and its R1 response is polled before CS is released. The
+    /// returned byte is the R1 status — `0x01` once the card has entered the
+    /// idle state — so the caller can confirm SPI mode was reached. This gives
+    /// a reliable cold-init path that the [`SpiDevice`]-based [`SpiTransport`]
+    /// cannot offer, because it never exposes transfers with CS held high.
+    ///
+    /// [`SpiDevice`]: embedded_hal::spi::SpiDevice
+    #[inline]
+    pub fn power_up_sequence(&mut self) -> Result<u8, SpiBusError<BUS::Error, CS::Error>> {
+        self.cs.set_high().map_err(SpiBusError::Pin)?;
+        self.bus.write(&[0xFF; 10]).map_err(SpiBusError::Bus)?;
+        self.select()?;
+        let buf = card_command_buffer(0, 0);
+        self.bus.write(&buf).map_err(SpiBusError::Bus)?;
+        // Poll for the R1 response; the card drives MISO high until it answers.
+        let mut r1 = 0xFF;
         for _ in 0..0xFF {
-            self.transfer_byte(0xFF)?;
+            r1 = self.transfer_byte(0xFF)?;
+            if r1 & 0x80 == 0 {
+                break;
+            }
         }
+        self.deselect()?;
+        Ok(r1)
+    }
+
+    /// Run `f` with the chip-select line held low for its whole duration.
+    ///
+    /// This is the CS scope that lets callers keep a command and the response
+    /// the card drives out on a continuously-asserted CS in a single
+    /// transaction — for example a command followed by
+    /// [`read_r1_with_timeout`](Transport::read_r1_with_timeout). CS is
+    /// asserted before `f` runs and deasserted (with a trailing `0xFF`) after,
+    /// even if `f` returns an error. Unlike [`SpiDevice`], the implementor
+    /// decides exactly which exchanges share one CS assertion.
+    ///
+    /// [`SpiDevice`]: embedded_hal::spi::SpiDevice
+    #[inline]
+    pub fn transaction<R, F>(&mut self, f: F) -> Result<R, SpiBusError<BUS::Error, CS::Error>>
+    where
+        F: FnOnce(&mut Self) -> Result<R, SpiBusError<BUS::Error, CS::Error>>,
+    {
+        self.select()?;
+        let res = f(self);
+        self.deselect()?;
+        res
+    }
+
+    /// Assert the chip-select line (drive it low) to begin a transaction.
+    #[inline]
+    pub fn select(&mut self) -> Result<(), SpiBusError<BUS::Error, CS::Error>> {
+        self.cs.set_low().map_err(SpiBusError::Pin)
+    }
+
+    /// Deassert the chip-select line (drive it high) and clock one trailing
+    /// `0xFF` so the card releases MISO, ending a transaction.
+    #[inline]
+    pub fn deselect(&mut self) -> Result<(), SpiBusError<BUS::Error, CS::Error>> {
+        self.cs.set_high().map_err(SpiBusError::Pin)?;
+        self.bus.write(&[0xFF]).map_err(SpiBusError::Bus)
+    }
+
+    /// Write a full single-block data packet as one CS-asserted transaction.
+    ///
+    /// The start token, payload and its CRC16 are clocked out and the
+    /// data-response token read back while CS stays low, so the packet and its
+    /// response belong to the same transaction. The response is decoded with
+    /// [`check_data_response`], mapping a CRC-error token to
+    /// [`TransportError::CrcMismatch`] and a write-error token to
+    /// [`TransportError::CardError`]. Block framing lives here, on the
+    /// CS-owning transport, because it cannot be expressed as per-transfer
+    /// primitives on a [`SpiDevice`].
+    ///
+    /// [`SpiDevice`]: embedded_hal::spi::SpiDevice
+    pub fn write_data_block(
+        &mut self,
+        buf: &[u8],
+    ) -> Result<(), TransportError<SpiBusError<BUS::Error, CS::Error>>> {
+        let crc = crc16(buf).to_be_bytes();
+        self.select().map_err(TransportError::Spi)?;
+        let token = self
+            .write_data_block_framed(buf, &crc)
+            .and_then(|()| self.transfer_byte(0xFF));
+        self.deselect().map_err(TransportError::Spi)?;
+        check_data_response(token.map_err(TransportError::Spi)?)
+    }
+
+    /// Clock out the packet body (start token, payload, CRC) with CS asserted.
+    #[inline]
+    fn write_data_block_framed(
+        &mut self,
+        buf: &[u8],
+        crc: &[u8; 2],
+    ) -> Result<(), SpiBusError<BUS::Error, CS::Error>> {
+        self.bus.write(&[DATA_START_BLOCK]).map_err(SpiBusError::Bus)?;
+        self.bus.write(buf).map_err(SpiBusError::Bus)?;
+        self.bus.write(crc).map_err(SpiBusError::Bus)
+    }
+
+    /// Read a full single-block data packet as one CS-asserted transaction.
+    ///
+    /// Waits for the [`DATA_START_BLOCK`] token (returning
+    /// [`TransportError::NoResponse`] if it never arrives), then reads the
+    /// payload and its two trailing CRC bytes, all with CS held low. With the
+    /// `crc-check` feature enabled the CRC is recomputed and compared, returning
+    /// [`TransportError::CrcMismatch`] on disagreement; without it the trailing
+    /// bytes are consumed but not verified, matching SPI mode's CRC-off default.
+    pub fn read_data_block(
+        &mut self,
+        buf: &mut [u8],
+    ) -> Result<(), TransportError<SpiBusError<BUS::Error, CS::Error>>> {
+        self.select().map_err(TransportError::Spi)?;
+        let res = self.read_data_block_framed(buf);
+        self.deselect().map_err(TransportError::Spi)?;
+        res
+    }
+
+    /// Poll for the start token and read payload + CRC with CS asserted.
+    fn read_data_block_framed(
+        &mut self,
+        buf: &mut [u8],
+    ) -> Result<(), TransportError<SpiBusError<BUS::Error, CS::Error>>> {
+        let mut token = 0xFF;
+        for _ in 0..0xFFFF {
+            token = self.transfer_byte(0xFF).map_err(TransportError::Spi)?;
+            if token != 0xFF {
+                break;
+            }
+        }
+        if token != DATA_START_BLOCK {
+            return Err(TransportError::NoResponse);
+        }
+        buf.fill(0xFF);
+        self.bus
+            .transfer_in_place(buf)
+            .map_err(|e| TransportError::Spi(SpiBusError::Bus(e)))?;
+        let mut crc = [0xFF; 2];
+        self.bus
+            .transfer_in_place(&mut crc)
+            .map_err(|e| TransportError::Spi(SpiBusError::Bus(e)))?;
+        #[cfg(feature = "crc-check")]
+        if crc16(buf) != u16::from_be_bytes(crc) {
+            return Err(TransportError::CrcMismatch);
+        }
+        #[cfg(not(feature = "crc-check"))]
+        let _ = crc;
         Ok(())
     }
+}
+
+impl<BUS, CS> SpiBusTransport<BUS, CS>
+where
+    BUS: embedded_hal::spi::SpiBus<u8>,
+    CS: embedded_hal::digital::OutputPin,
+{
+    /// Send one byte and receive one byte from the card.
+    #[inline]
+    fn transfer_byte(&mut self, byte: u8) -> Result<u8, SpiBusError<BUS::Error, CS::Error>> {
+        let mut read_buf = [0u8; 1];
+        self.bus
+            .transfer(&mut read_buf, &[byte])
+            .map_err(SpiBusError::Bus)?;
+        Ok(read_buf[0])
+    }
+}
+
+impl<BUS, CS> Transport for SpiBusTransport<BUS, CS>
+where
+    BUS: embedded_hal::spi::SpiBus<u8>,
+    CS: embedded_hal::digital::OutputPin,
+{
+    type Error = SpiBusError<BUS::Error, CS::Error>;
+
+    // These primitives operate on the bus with whatever CS state the caller has
+    // set up; group a command and its response under one CS assertion with
+    // [`transaction`](SpiBusTransport::transaction) (or [`select`] / [`deselect`]).
+    //
+    // [`select`]: SpiBusTransport::select
+    // [`deselect`]: SpiBusTransport::deselect
+
+    #[inline]
+    fn write_card_command(&mut self, command: u8, arg: u32) -> Result<(), Self::Error> {
+        let buf = card_command_buffer(command, arg);
+        self.bus.write(&buf).map_err(SpiBusError::Bus)
+    }
+
+    #[inline]
+    fn read_card_response_u8(&mut self) -> Result<u8, Self::Error> {
+        let mut read_buf = [0];
+        let write_buf = [0xFF];
+        self.bus
+            .transfer(&mut read_buf, &write_buf)
+            .map_err(SpiBusError::Bus)?;
+        Ok(read_buf[0])
+    }
+
+    #[inline]
+    fn read_card_response_u32(&mut self) -> Result<u32, Self::Error> {
+        let mut read_buf = [0; 4];
+        let write_buf = [0xFF; 4];
+        self.bus
+            .transfer(&mut read_buf, &write_buf)
+            .map_err(SpiBusError::Bus)?;
+        Ok(u32::from_be_bytes(read_buf))
+    }
+
+    #[inline]
+    fn read_card_response_u128(&mut self) -> Result<u128, Self::Error> {
+        let mut read_buf = [0; 16];
+        let write_buf = [0xFF; 16];
+        self.bus
+            .transfer(&mut read_buf, &write_buf)
+            .map_err(SpiBusError::Bus)?;
+        Ok(u128::from_be_bytes(read_buf))
+    }
+
+    #[inline]
+    fn write_data(&mut self, buf: &[u8]) -> Result<(), Self::Error> {
+        self.bus.write(buf).map_err(SpiBusError::Bus)
+    }
+
+    #[inline]
+    fn read_data(&mut self, buf: &mut [u8]) -> Result<(), Self::Error> {
+        buf.fill(0xFF);
+        self.bus.transfer_in_place(buf).map_err(SpiBusError::Bus)
+    }
 
     #[inline]
     fn is_busy(&mut self) -> Result<bool, Self::Error> {